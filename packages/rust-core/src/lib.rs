@@ -1,6 +1,167 @@
+#![feature(portable_simd)]
+
 use wasm_bindgen::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_wasm_bindgen::{from_value, to_value};
+use std::simd::prelude::*;
+use std::slice;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+use rayon::prelude::*;
+
+/// Spins up the rayon worker pool from JS; call before any threaded export.
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
+/// Lane width used by the SIMD reduction kernels below.
+const LANES: usize = 8;
+
+/// Multi-accumulator SIMD sum; scalar tail handled separately.
+fn simd_sum(arr: &[f64]) -> f64 {
+    let mut acc = f64x8::splat(0.0);
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        acc += f64x8::from_slice(chunk);
+    }
+
+    let mut total = acc.reduce_sum();
+    for x in remainder {
+        total += x;
+    }
+    total
+}
+
+#[cfg(test)]
+mod simd_sum_tests {
+    use super::*;
+
+    #[test]
+    fn simd_sum_agrees_with_scalar_sum_past_one_lane() {
+        // 13 isn't a multiple of LANES (8), so this exercises the scalar tail.
+        let arr: Vec<f64> = (1..=13).map(|x| x as f64).collect();
+        let scalar: f64 = arr.iter().sum();
+        assert!((simd_sum(&arr) - scalar).abs() < 1e-9);
+    }
+
+    #[test]
+    fn simd_sum_empty_is_zero() {
+        assert_eq!(simd_sum(&[]), 0.0);
+    }
+}
+
+/// Same multi-accumulator structure as `simd_sum`, but squares each lane
+/// before accumulating. Used by `MeanSquare` and `L2Norm`.
+fn simd_sum_sq(arr: &[f64]) -> f64 {
+    let mut acc = f64x8::splat(0.0);
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        let v = f64x8::from_slice(chunk);
+        acc += v * v;
+    }
+
+    let mut total = acc.reduce_sum();
+    for x in remainder {
+        total += x * x;
+    }
+    total
+}
+
+fn simd_product(arr: &[f64]) -> f64 {
+    let mut acc = f64x8::splat(1.0);
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        acc *= f64x8::from_slice(chunk);
+    }
+
+    let mut total = acc.reduce_product();
+    for x in remainder {
+        total *= x;
+    }
+    total
+}
+
+fn simd_min(arr: &[f64]) -> f64 {
+    let mut acc = f64x8::splat(f64::INFINITY);
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        acc = acc.simd_min(f64x8::from_slice(chunk));
+    }
+
+    let mut total = acc.reduce_min();
+    for &x in remainder {
+        total = total.min(x);
+    }
+    total
+}
+
+fn simd_max(arr: &[f64]) -> f64 {
+    let mut acc = f64x8::splat(f64::NEG_INFINITY);
+    let chunks = arr.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        acc = acc.simd_max(f64x8::from_slice(chunk));
+    }
+
+    let mut total = acc.reduce_max();
+    for &x in remainder {
+        total = total.max(x);
+    }
+    total
+}
+
+#[cfg(test)]
+mod simd_fold_tests {
+    use super::*;
+
+    // 13 isn't a multiple of LANES (8), so this exercises the scalar tail.
+    const ARR: [f64; 13] = [
+        3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0, 9.0,
+    ];
+
+    #[test]
+    fn simd_sum_sq_agrees_with_scalar_reference() {
+        let scalar: f64 = ARR.iter().map(|x| x * x).sum();
+        assert!((simd_sum_sq(&ARR) - scalar).abs() < 1e-9);
+    }
+
+    #[test]
+    fn simd_product_agrees_with_scalar_reference() {
+        let scalar: f64 = ARR.iter().product();
+        assert!((simd_product(&ARR) - scalar).abs() < scalar.abs() * 1e-9);
+    }
+
+    #[test]
+    fn simd_min_agrees_with_scalar_reference() {
+        let scalar = ARR.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert_eq!(simd_min(&ARR), scalar);
+    }
+
+    #[test]
+    fn simd_max_agrees_with_scalar_reference() {
+        let scalar = ARR.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(simd_max(&ARR), scalar);
+    }
+
+    #[test]
+    fn simd_min_max_empty_use_identity() {
+        assert_eq!(simd_min(&[]), f64::INFINITY);
+        assert_eq!(simd_max(&[]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn simd_product_empty_is_one() {
+        assert_eq!(simd_product(&[]), 1.0);
+    }
+}
 
 /// ===============================
 /// MAP
@@ -30,10 +191,41 @@ pub struct RangePayload {
 pub fn parallel_for_chunk(input: JsValue) -> f64 {
     let payload: RangePayload = from_value(input).unwrap();
 
-    let mut sum: f64 = 0.0;
+    #[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+    {
+        parallel_for_threaded(payload.start, payload.end)
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
+    {
+        parallel_for_serial(payload.start, payload.end)
+    }
+}
+
+/// Rayon work-stealing path; requires JS to have called `init_thread_pool` first.
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+fn parallel_for_threaded(start: u32, end: u32) -> f64 {
+    (start..end).into_par_iter().map(|i| (i as f64) * (i as f64)).sum()
+}
+
+/// Fallback used when the atomics/shared-memory WASM target isn't available.
+fn parallel_for_serial(start: u32, end: u32) -> f64 {
+    let start_f = start as f64;
+    let len = end.saturating_sub(start) as usize;
+
+    // i, i+1, ..., i+LANES-1 as a vector, squared and accumulated LANES at a time.
+    let lane_offsets = f64x8::from_array([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    let mut acc = f64x8::splat(0.0);
+
+    let full_chunks = len / LANES;
+    for c in 0..full_chunks {
+        let base = start_f + (c * LANES) as f64;
+        let idx = f64x8::splat(base) + lane_offsets;
+        acc += idx * idx;
+    }
 
-    for i in payload.start..payload.end {
-        let val = i as f64;
+    let mut sum = acc.reduce_sum();
+    for i in (full_chunks * LANES)..len {
+        let val = start_f + i as f64;
         sum += val * val;
     }
 
@@ -46,7 +238,7 @@ pub fn parallel_for_chunk(input: JsValue) -> f64 {
 #[wasm_bindgen]
 pub fn reduce_sum(input: JsValue) -> f64 {
     let arr: Vec<f64> = from_value(input).unwrap();
-    arr.iter().sum()
+    reduce_sum_slice(&arr)
 }
 
 #[wasm_bindgen]
@@ -57,6 +249,421 @@ pub fn reduce_avg(input: JsValue) -> f64 {
         return 0.0;
     }
 
-    let sum: f64 = arr.iter().sum();
-    sum / arr.len() as f64
+    reduce_sum_slice(&arr) / arr.len() as f64
+}
+
+/// Shared by `reduce_sum`, `reduce_avg` and `stats_summary`: threaded when atomics are
+/// available, SIMD otherwise.
+fn reduce_sum_slice(arr: &[f64]) -> f64 {
+    #[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+    {
+        arr.par_iter().sum()
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
+    {
+        simd_sum(arr)
+    }
+}
+
+/// ===============================
+/// SHARED MEMORY BUFFERS
+/// ===============================
+/// JS writes directly into `wasm.memory.buffer` at the offset returned by
+/// `alloc_f64`; the `_ptr` functions below read/write that same memory in
+/// place. Free with `dealloc_f64` once JS is done with it.
+
+#[wasm_bindgen]
+pub fn alloc_f64(len: usize) -> *mut f64 {
+    let mut buf: Vec<f64> = vec![0.0; len];
+    let ptr = buf.as_mut_ptr();
+    std::mem::forget(buf);
+    ptr
+}
+
+/// # Safety
+/// `ptr` must have come from `alloc_f64(len)` and not have been freed already.
+#[wasm_bindgen]
+pub unsafe fn dealloc_f64(ptr: *mut f64, len: usize) {
+    drop(Vec::from_raw_parts(ptr, len, len));
+}
+
+/// # Safety
+/// `ptr` must point to `len` valid, non-aliased `f64`s (e.g. from `alloc_f64`).
+#[wasm_bindgen]
+pub unsafe fn map_square_inplace(ptr: *mut f64, len: usize) {
+    let arr = slice::from_raw_parts_mut(ptr, len);
+    for x in arr.iter_mut() {
+        *x *= *x;
+    }
+}
+
+/// # Safety
+/// `ptr` must point to `len` valid `f64`s (e.g. from `alloc_f64`).
+#[wasm_bindgen]
+pub unsafe fn reduce_sum_ptr(ptr: *const f64, len: usize) -> f64 {
+    simd_sum(slice::from_raw_parts(ptr, len))
+}
+
+/// # Safety
+/// `ptr` must point to `len` valid `f64`s (e.g. from `alloc_f64`).
+#[wasm_bindgen]
+pub unsafe fn reduce_avg_ptr(ptr: *const f64, len: usize) -> f64 {
+    let arr = slice::from_raw_parts(ptr, len);
+
+    if arr.is_empty() {
+        return 0.0;
+    }
+
+    simd_sum(arr) / arr.len() as f64
+}
+
+#[cfg(test)]
+mod buffer_tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_alloc_write_square_reduce_dealloc() {
+        let len = 5;
+        let ptr = alloc_f64(len);
+
+        unsafe {
+            let slice = slice::from_raw_parts_mut(ptr, len);
+            slice.copy_from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+            map_square_inplace(ptr, len);
+            assert_eq!(slice, &[1.0, 4.0, 9.0, 16.0, 25.0]);
+
+            assert_eq!(reduce_sum_ptr(ptr, len), 55.0);
+            assert_eq!(reduce_avg_ptr(ptr, len), 11.0);
+
+            dealloc_f64(ptr, len);
+        }
+    }
+
+    #[test]
+    fn reduce_avg_ptr_empty_is_zero() {
+        let ptr = alloc_f64(0);
+        unsafe {
+            assert_eq!(reduce_avg_ptr(ptr, 0), 0.0);
+            dealloc_f64(ptr, 0);
+        }
+    }
+}
+
+/// ===============================
+/// DESCRIPTIVE STATISTICS
+/// ===============================
+#[derive(Serialize)]
+pub struct StatsSummary {
+    pub sum: f64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub variance: f64,
+    pub std_dev: f64,
+    pub median: f64,
+}
+
+/// Percentile of an already-sorted slice via linear interpolation. `p` is
+/// clamped to `0..=100`; returns `NaN` for an empty slice.
+fn percentile_sorted(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let p = p.clamp(0.0, 100.0);
+    let rank = (p / 100.0) * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+
+    if lo == hi {
+        sorted[lo]
+    } else {
+        let frac = rank - lo as f64;
+        sorted[lo] + (sorted[hi] - sorted[lo]) * frac
+    }
+}
+
+#[wasm_bindgen]
+pub fn percentile(input: JsValue, p: f64) -> f64 {
+    let arr: Vec<f64> = from_value(input).unwrap();
+    let mut sorted = arr;
+    sorted.sort_unstable_by(f64::total_cmp);
+    percentile_sorted(&sorted, p)
+}
+
+#[wasm_bindgen]
+pub fn stats_summary(input: JsValue) -> JsValue {
+    let arr: Vec<f64> = from_value(input).unwrap();
+    to_value(&compute_stats(&arr)).unwrap()
+}
+
+fn compute_stats(arr: &[f64]) -> StatsSummary {
+    if arr.is_empty() {
+        return StatsSummary {
+            sum: 0.0,
+            mean: f64::NAN,
+            min: f64::NAN,
+            max: f64::NAN,
+            variance: f64::NAN,
+            std_dev: f64::NAN,
+            median: f64::NAN,
+        };
+    }
+
+    // Welford's online algorithm: avoids the catastrophic cancellation of
+    // computing variance from a naive sum-of-squares.
+    let mut count = 0u32;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+
+    for &x in arr {
+        count += 1;
+        let delta = x - mean;
+        mean += delta / count as f64;
+        m2 += delta * (x - mean);
+
+        if x < min {
+            min = x;
+        }
+        if x > max {
+            max = x;
+        }
+    }
+
+    let variance = if count > 1 {
+        m2 / (count - 1) as f64
+    } else {
+        0.0
+    };
+
+    let mut sorted = arr.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    let median = percentile_sorted(&sorted, 50.0);
+
+    StatsSummary {
+        sum: reduce_sum_slice(arr),
+        mean,
+        min,
+        max,
+        variance,
+        std_dev: variance.sqrt(),
+        median,
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+
+    const SAMPLE: [f64; 5] = [2.0, 4.0, 4.0, 4.0, 6.0];
+
+    #[test]
+    fn compute_stats_matches_known_mean_and_variance() {
+        let s = compute_stats(&SAMPLE);
+        assert_eq!(s.sum, 20.0);
+        assert_eq!(s.mean, 4.0);
+        assert_eq!(s.min, 2.0);
+        assert_eq!(s.max, 6.0);
+        assert!((s.variance - 2.0).abs() < 1e-12);
+        assert!((s.std_dev - 2.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(s.median, 4.0);
+    }
+
+    #[test]
+    fn compute_stats_empty_is_nan() {
+        let s = compute_stats(&[]);
+        assert_eq!(s.sum, 0.0);
+        assert!(s.mean.is_nan());
+        assert!(s.variance.is_nan());
+        assert!(s.median.is_nan());
+    }
+
+    #[test]
+    fn compute_stats_single_element_has_zero_variance() {
+        let s = compute_stats(&[7.0]);
+        assert_eq!(s.mean, 7.0);
+        assert_eq!(s.variance, 0.0);
+        assert_eq!(s.median, 7.0);
+    }
+
+    #[test]
+    fn percentile_sorted_matches_known_ranks() {
+        let mut sorted = SAMPLE;
+        sorted.sort_unstable_by(f64::total_cmp);
+        assert_eq!(percentile_sorted(&sorted, 0.0), 2.0);
+        assert_eq!(percentile_sorted(&sorted, 50.0), 4.0);
+        assert_eq!(percentile_sorted(&sorted, 100.0), 6.0);
+        assert_eq!(percentile_sorted(&sorted, 25.0), 4.0);
+    }
+
+    #[test]
+    fn percentile_sorted_clamps_out_of_range_p() {
+        let mut sorted = SAMPLE;
+        sorted.sort_unstable_by(f64::total_cmp);
+        assert_eq!(percentile_sorted(&sorted, 150.0), percentile_sorted(&sorted, 100.0));
+        assert_eq!(percentile_sorted(&sorted, -50.0), percentile_sorted(&sorted, 0.0));
+    }
+
+    #[test]
+    fn percentile_sorted_empty_is_nan() {
+        assert!(percentile_sorted(&[], 50.0).is_nan());
+    }
+
+    #[test]
+    fn percentile_sorted_single_element() {
+        assert_eq!(percentile_sorted(&[9.0], 37.0), 9.0);
+    }
+}
+
+/// ===============================
+/// SPECTRAL NORM
+/// ===============================
+/// `A(i,j) = 1 / ((i+j)*(i+j+1)/2 + i + 1)`, matrix-free.
+fn a_entry(i: usize, j: usize) -> f64 {
+    let ij = (i + j) as f64;
+    1.0 / (ij * (ij + 1.0) / 2.0 + i as f64 + 1.0)
+}
+
+fn mult_av(v: &[f64], out: &mut [f64]) {
+    let n = v.len();
+    for (i, out_i) in out.iter_mut().enumerate() {
+        let mut acc0 = 0.0;
+        let mut acc1 = 0.0;
+        let mut j = 0;
+        while j + 1 < n {
+            acc0 += a_entry(i, j) * v[j];
+            acc1 += a_entry(i, j + 1) * v[j + 1];
+            j += 2;
+        }
+        let mut sum = acc0 + acc1;
+        if j < n {
+            sum += a_entry(i, j) * v[j];
+        }
+        *out_i = sum;
+    }
+}
+
+fn mult_atv(v: &[f64], out: &mut [f64]) {
+    let n = v.len();
+    for (i, out_i) in out.iter_mut().enumerate() {
+        let mut acc0 = 0.0;
+        let mut acc1 = 0.0;
+        let mut j = 0;
+        while j + 1 < n {
+            acc0 += a_entry(j, i) * v[j];
+            acc1 += a_entry(j + 1, i) * v[j + 1];
+            j += 2;
+        }
+        let mut sum = acc0 + acc1;
+        if j < n {
+            sum += a_entry(j, i) * v[j];
+        }
+        *out_i = sum;
+    }
+}
+
+fn mult_at_av(v: &[f64], out: &mut [f64], tmp: &mut [f64]) {
+    mult_av(v, tmp);
+    mult_atv(tmp, out);
+}
+
+#[wasm_bindgen]
+pub fn spectral_norm(n: usize) -> f64 {
+    let mut u = vec![1.0; n];
+    let mut v = vec![0.0; n];
+    let mut tmp = vec![0.0; n];
+
+    for _ in 0..10 {
+        mult_at_av(&u, &mut v, &mut tmp);
+        mult_at_av(&v, &mut u, &mut tmp);
+    }
+
+    let vbv: f64 = u.iter().zip(v.iter()).map(|(a, b)| a * b).sum();
+    let vv: f64 = v.iter().map(|x| x * x).sum();
+    (vbv / vv).sqrt()
+}
+
+#[cfg(test)]
+mod spectral_norm_tests {
+    use super::*;
+
+    #[test]
+    fn spectral_norm_100_matches_known_reference() {
+        let got = spectral_norm(100);
+        assert!((got - 1.274_219_991).abs() < 1e-9, "got {got}");
+    }
+}
+
+/// ===============================
+/// GENERIC REDUCE
+/// ===============================
+#[derive(Deserialize)]
+pub enum ReduceOp {
+    Sum,
+    Product,
+    Min,
+    Max,
+    MeanSquare,
+    L2Norm,
+}
+
+/// Dispatches to a single vectorized fold keyed by `op`. Identity/empty
+/// result per op: `Sum` -> 0, `Product` -> 1, `Min` -> `+INF`, `Max` ->
+/// `-INF`, `MeanSquare`/`L2Norm` -> 0.
+#[wasm_bindgen]
+pub fn reduce(input: JsValue, op: JsValue) -> f64 {
+    let arr: Vec<f64> = from_value(input).unwrap();
+    let op: ReduceOp = from_value(op).unwrap();
+
+    #[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+    {
+        reduce_threaded(&arr, &op)
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
+    {
+        reduce_serial(&arr, &op)
+    }
+}
+
+/// Rayon-backed fold, mirroring `reduce_serial` op-for-op.
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+fn reduce_threaded(arr: &[f64], op: &ReduceOp) -> f64 {
+    match op {
+        ReduceOp::Sum => arr.par_iter().sum(),
+        ReduceOp::Product => arr.par_iter().product(),
+        ReduceOp::Min => arr.par_iter().cloned().reduce(|| f64::INFINITY, f64::min),
+        ReduceOp::Max => arr.par_iter().cloned().reduce(|| f64::NEG_INFINITY, f64::max),
+        ReduceOp::MeanSquare => {
+            if arr.is_empty() {
+                0.0
+            } else {
+                arr.par_iter().map(|x| x * x).sum::<f64>() / arr.len() as f64
+            }
+        }
+        ReduceOp::L2Norm => arr.par_iter().map(|x| x * x).sum::<f64>().sqrt(),
+    }
+}
+
+/// SIMD-accumulated fallback for non-threaded targets.
+fn reduce_serial(arr: &[f64], op: &ReduceOp) -> f64 {
+    match op {
+        ReduceOp::Sum => simd_sum(arr),
+        ReduceOp::Product => simd_product(arr),
+        ReduceOp::Min => simd_min(arr),
+        ReduceOp::Max => simd_max(arr),
+        ReduceOp::MeanSquare => {
+            if arr.is_empty() {
+                0.0
+            } else {
+                simd_sum_sq(arr) / arr.len() as f64
+            }
+        }
+        ReduceOp::L2Norm => simd_sum_sq(arr).sqrt(),
+    }
 }
\ No newline at end of file